@@ -0,0 +1,119 @@
+//! Board-specific fan curve parameters.
+//!
+//! A [`Board`] carries everything the curve encoder needs to know about one
+//! physical board: the ACPI method used to push a curve to the EC, the EC
+//! address of each fan, and the safety limits enforced by
+//! [`Curve::check_safety`](crate::Curve::check_safety). New boards are added
+//! by implementing this trait, not by branching inside the encoder.
+
+use std::fmt;
+
+use crate::Fan;
+
+/// Safety limits for a single point of a fan curve.
+///
+/// See [`Board::fan_limits`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FanLimits {
+    pub min_temp: u8,
+    pub max_temp: u8,
+    pub min_speed: u8,
+}
+
+/// A board capable of accepting a fan curve.
+pub trait Board: fmt::Debug {
+    /// The board name as reported by `/sys/class/dmi/id/board_name`.
+    fn name(&self) -> &'static str;
+
+    /// The ACPI method used to write a fan curve to the EC.
+    fn acpi_method(&self) -> &str;
+
+    /// The ACPI method used to read the currently-programmed fan curve back from the EC.
+    fn acpi_read_method(&self) -> &str;
+
+    /// The ACPI method used to hand a fan back to firmware/EC-managed automatic control.
+    fn acpi_auto_method(&self) -> &str;
+
+    /// The EC address for `fan`, or `None` if this board doesn't have it.
+    fn fan_address(&self, fan: Fan) -> Option<u32>;
+
+    /// Safety limits for curve point `index` (0..=7) of `fan`.
+    ///
+    /// See [`Curve::check_safety`](crate::Curve::check_safety).
+    fn fan_limits(&self, fan: Fan, index: u8) -> FanLimits;
+}
+
+/// The ASUS ROG Zephyrus G14 (2020), board name `GA401IV`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ga401;
+
+impl Board for Ga401 {
+    fn name(&self) -> &'static str {
+        "GA401IV"
+    }
+
+    fn acpi_method(&self) -> &str {
+        "\\_SB.PCI0.SBRG.EC0.SUFC"
+    }
+
+    // UNVERIFIED: unlike `SUFC` (which matches the write call this crate has
+    // always made), this read-side method name is a best guess at the "get"
+    // counterpart and hasn't been confirmed against `asusctl`/a GA401IV DSDT
+    // dump. Don't trust it on real hardware without checking first.
+    fn acpi_read_method(&self) -> &str {
+        "\\_SB.PCI0.SBRG.EC0.SUFG"
+    }
+
+    // UNVERIFIED: same caveat as `acpi_read_method` above — this method name
+    // isn't from the original code or the feature request, and hasn't been
+    // confirmed against `asusctl`/a GA401IV DSDT dump. Don't trust it on
+    // real hardware without checking first.
+    fn acpi_auto_method(&self) -> &str {
+        "\\_SB.PCI0.SBRG.EC0.SFAN"
+    }
+
+    fn fan_address(&self, fan: Fan) -> Option<u32> {
+        match fan {
+            Fan::Cpu => Some(0x40),
+            Fan::Gpu => Some(0x44),
+        }
+    }
+
+    fn fan_limits(&self, fan: Fan, index: u8) -> FanLimits {
+        let min_temp = (index * 10) + 30;
+        let max_temp = (index * 10) + 39;
+
+        let min_speed = match index {
+            0 | 1 | 2 | 3 => 0,
+            4 => match fan {
+                Fan::Cpu => 31,
+                Fan::Gpu => 34,
+            },
+            5 => match fan {
+                Fan::Cpu => 49,
+                Fan::Gpu => 51,
+            },
+            6 | 7 => match fan {
+                Fan::Cpu => 56,
+                Fan::Gpu => 61,
+            },
+            _ => unreachable!("Invalid fan index"),
+        };
+
+        FanLimits { min_temp, max_temp, min_speed }
+    }
+}
+
+/// Looks up a board by its `board_name` string (e.g. `GA401IV`).
+pub fn from_name(name: &str) -> Option<Box<dyn Board>> {
+    match name {
+        "GA401IV" => Some(Box::new(Ga401)),
+        _ => None,
+    }
+}
+
+/// Gets the board name from the kernel using `/sys/class/dmi/id/board_name`.
+pub fn from_board_name() -> Option<Box<dyn Board>> {
+    let name = std::fs::read_to_string("/sys/class/dmi/id/board_name").ok()?;
+    from_name(name.trim())
+}
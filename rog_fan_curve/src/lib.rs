@@ -21,10 +21,10 @@
 //! curve.set_point(6,  90, 100);
 //! curve.set_point(7, 100, 100);
 //! 
-//! let board = Board::from_name("GA401IV").unwrap();
-//! 
-//! curve.apply(board, Fan::Cpu)?;
-//! curve.apply(board, Fan::Gpu)?;
+//! let board = rog_fan_curve::from_name("GA401IV").unwrap();
+//!
+//! curve.apply(board.as_ref(), Fan::Cpu)?;
+//! curve.apply(board.as_ref(), Fan::Gpu)?;
 //! 
 //! # Ok(())
 //! # }
@@ -82,9 +82,27 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # Config profiles
+//!
+//! The `config` feature (which implies `serde`) adds [`Profiles`], a TOML
+//! document of named curves that can be loaded, saved and applied to a
+//! board in one call.
+//!
+//! In `Cargo.toml`
+//! ```toml
+//! rog_fan_curve = { version = "*", features = ["config"] }
+//! ```
 
 #[cfg(feature = "serde")]
 mod serde_impl;
+#[cfg(feature = "config")]
+mod profile;
+mod board;
+
+pub use board::{Board, FanLimits, Ga401, from_name, from_board_name};
+#[cfg(feature = "config")]
+pub use profile::{Profiles, ProfileCurves};
 
 use std::io::prelude::*;
 use std::fmt;
@@ -95,6 +113,14 @@ pub enum CurveError {
     Acpi(String),
     InvalidFan(Fan),
     Io(std::io::Error),
+    #[cfg(feature = "config")]
+    UnknownProfile(String),
+    #[cfg(feature = "config")]
+    BoardMismatch { expected: String, actual: String },
+    #[cfg(feature = "config")]
+    Toml(toml::de::Error),
+    #[cfg(feature = "config")]
+    TomlSer(toml::ser::Error),
 }
 
 impl fmt::Display for CurveError {
@@ -109,6 +135,22 @@ impl fmt::Display for CurveError {
             Self::Io(err) => {
                 write!(f, "failed to write to file {}", err)
             }
+            #[cfg(feature = "config")]
+            Self::UnknownProfile(name) => {
+                write!(f, "No profile named `{}`.", name)
+            }
+            #[cfg(feature = "config")]
+            Self::BoardMismatch { expected, actual } => {
+                write!(f, "Profile is for board `{}`, but `{}` was selected.", expected, actual)
+            }
+            #[cfg(feature = "config")]
+            Self::Toml(err) => {
+                write!(f, "failed to parse profiles TOML: {}", err)
+            }
+            #[cfg(feature = "config")]
+            Self::TomlSer(err) => {
+                write!(f, "failed to serialize profiles TOML: {}", err)
+            }
         }
     }
 }
@@ -119,6 +161,14 @@ impl std::error::Error for CurveError {
             Self::Io(err) => {
                 Some(err)
             }
+            #[cfg(feature = "config")]
+            Self::Toml(err) => {
+                Some(err)
+            }
+            #[cfg(feature = "config")]
+            Self::TomlSer(err) => {
+                Some(err)
+            }
             _ => None
         }
     }
@@ -220,48 +270,29 @@ impl Curve {
     
     /// Checks if the curve is "safe"
     ///
-    /// This checks if the curve falls within some arbitrary limitations.
+    /// This checks if the curve falls within `board`'s limitations.
     /// The limitations should match the ones used by [atrofac](https://github.com/cronosun/atrofac/blob/master/ADVANCED.md#limits)
     /// and armoury crate.
-    pub fn check_safety(&self, fan: Fan) -> Result<(), UnsafeCurveError> {
+    pub fn check_safety(&self, board: &dyn Board, fan: Fan) -> Result<(), UnsafeCurveError> {
         let mut last_speed = 0;
         for i in 0..8 {
             let temp = self.curve[i];
             let speed = self.curve[i+8];
-            
+
             let i = i as u8;
-            
-            let min_temp = (i * 10) + 30;
-            let max_temp = (i * 10) + 39;
-            
-            let min_speed = match i {
-                0 | 1 | 2 | 3 => 0,
-                4 => match fan {
-                    Fan::Cpu => 31,
-                    Fan::Gpu => 34,
-                },
-                5 => match fan {
-                    Fan::Cpu => 49,
-                    Fan::Gpu => 51,
-                },
-                6 | 7 => match fan {
-                    Fan::Cpu => 56,
-                    Fan::Gpu => 61,
-                },
-                _ => unreachable!("Invalid fan index"),
-            };
-            
-            if temp < min_temp || temp > max_temp {
+            let limits = board.fan_limits(fan, i);
+
+            if temp < limits.min_temp || temp > limits.max_temp {
                 return Err(UnsafeCurveError::TempOutOfRange(i))
             }
-            
-            if speed < min_speed || speed < last_speed {
+
+            if speed < limits.min_speed || speed < last_speed {
                 return Err(UnsafeCurveError::SpeedTooLow(i))
             }
-            
+
             last_speed = speed;
         }
-        
+
         Ok(())
     }
     
@@ -282,34 +313,105 @@ impl Curve {
         self.curve[n] = temp;
         self.curve[n+8] = speed;
     }
-    
+
+    /// Creates a `Curve` from a quadratic temperature -> speed function.
+    ///
+    /// For each of the eight fixed bucket temperatures `T` in `{30, 40, .., 100}`,
+    /// this normalizes `x = (T - 30) / 70` into `[0, 1]`, evaluates
+    /// `s = a*x^2 + b*x + c`, clamps `s` to `[0, 1]` and scales it to a 0-100
+    /// fan speed. The resulting points are then forced to be monotonically
+    /// non-decreasing.
+    ///
+    /// If `board` is given, each point is additionally raised to `board`'s
+    /// safety minimum for `fan` (see [`Curve::check_safety`]). Pass `None` to
+    /// get the plain quadratic curve with no board-specific clamping.
+    ///
+    /// `a = 0, b = 1, c = 0` yields a linear ramp from 0% at 30C to 100% at
+    /// 100C. See [`Curve::default_coefficients`] for coefficients that
+    /// approximate the hand-tuned default curve used by [`Curve::new`].
+    pub fn from_coefficients(a: f32, b: f32, c: f32, fan: Fan, board: Option<&dyn Board>) -> Self {
+        let mut curve = Curve::new();
+        let mut last_speed = 0;
+
+        for i in 0..8u8 {
+            let temp = 30 + i * 10;
+            let x = (temp as f32 - 30.0) / 70.0;
+            let s = (a * x * x + b * x + c).clamp(0.0, 1.0);
+
+            let mut speed = (s * 100.0).round() as u8;
+            if speed < last_speed {
+                speed = last_speed;
+            }
+
+            if let Some(board) = board {
+                let min_speed = board.fan_limits(fan, i).min_speed;
+                if speed < min_speed {
+                    speed = min_speed;
+                }
+            }
+
+            curve.set_point(i, temp, speed);
+            last_speed = speed;
+        }
+
+        curve
+    }
+
+    /// Coefficients that approximate the hand-tuned default curve used by [`Curve::new`].
+    ///
+    /// This is a quadratic fit, not an exact reproduction: the hand-tuned
+    /// curve jumps from 64% at 80C to 100% at 90C, more sharply than this parabola does.
+    pub fn default_coefficients() -> (f32, f32, f32) {
+        (0.86, -0.04, 0.18)
+    }
+
+    /// Creates a `Curve` by resampling arbitrary points onto the eight fixed bucket temperatures.
+    ///
+    /// The EC demands exactly eight points pinned at 30/40/.../100C, so
+    /// `points` - any sorted list of `(temperature, speed)` pairs - is
+    /// piecewise-linearly interpolated onto those buckets. For a bucket
+    /// temperature below the first point or above the last, the nearest
+    /// endpoint's speed is held flat; there's no extrapolation.
+    ///
+    /// `points` must contain at least two pairs with strictly increasing temperatures.
+    pub fn from_points(points: &[(u8, u8)]) -> Result<Self, String> {
+        if points.len() < 2 {
+            return Err("At least two points are required.".into())
+        }
+
+        for pair in points.windows(2) {
+            if pair[1].0 <= pair[0].0 {
+                return Err("Points must be strictly increasing in temperature.".into())
+            }
+        }
+
+        let mut curve = Curve::new();
+
+        for i in 0..8u8 {
+            let temp = 30 + i * 10;
+            let speed = interpolate_speed(points, temp);
+            curve.set_point(i, temp, speed);
+        }
+
+        Ok(curve)
+    }
+
     /// Applies the fan curve via acpi_call
-    pub fn apply(&self, board: Board, fan: Fan) -> Result<(), CurveError> {
-        assert_eq!(board, Board::Ga401);
-        let fan_addr = fan.address().ok_or(CurveError::InvalidFan(fan))?;
-        let command = make_command(self, fan_addr);
+    pub fn apply(&self, board: &dyn Board, fan: Fan) -> Result<(), CurveError> {
+        let fan_addr = board.fan_address(fan).ok_or(CurveError::InvalidFan(fan))?;
+        let command = make_command(self, board.acpi_method(), fan_addr);
         // dbg!(&command);
         acpi_call(&command)
     }
-}
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum Board {
-    Ga401,
-}
-
-impl Board {
-    /// Gets the board name from the kernel using `/sys/class/dmi/id/board_name`
-    pub fn from_board_name() -> Option<Self> {
-        let name = std::fs::read_to_string("/sys/class/dmi/id/board_name").ok()?;
-        Self::from_name(name.trim())
-    }
-    
-    pub fn from_name(name: &str) -> Option<Self> {
-        match name {
-            "GA401IV" => Some(Board::Ga401),
-            _ => None,
-        }
+    /// Reads the currently-programmed fan curve back from the EC via acpi_call.
+    ///
+    /// Useful for diffing against a desired curve before writing it with [`Curve::apply`].
+    pub fn read(board: &dyn Board, fan: Fan) -> Result<Curve, CurveError> {
+        let fan_addr = board.fan_address(fan).ok_or(CurveError::InvalidFan(fan))?;
+        let command = format!("{} {:#x}", board.acpi_read_method(), fan_addr);
+        let response = acpi_call_read(&command)?;
+        parse_acpi_response(&response)
     }
 }
 
@@ -321,17 +423,44 @@ pub enum Fan {
 }
 
 impl Fan {
-    fn address(&self) -> Option<u32> {
-        match self {
-            Fan::Cpu => Some(0x40),
-            Fan::Gpu => Some(0x44),
+    /// Hands this fan back to firmware/EC-managed automatic control.
+    ///
+    /// Useful for undoing a custom curve applied with [`Curve::apply`]
+    /// without a reboot.
+    pub fn set_auto(&self, board: &dyn Board) -> Result<(), CurveError> {
+        let fan_addr = board.fan_address(*self).ok_or(CurveError::InvalidFan(*self))?;
+        let command = format!("{} {:#x}", board.acpi_auto_method(), fan_addr);
+        acpi_call(&command)
+    }
+}
+
+/// Piecewise-linearly interpolates `points` (sorted, strictly increasing in
+/// temperature) at `temp`, holding the nearest endpoint flat outside their range.
+fn interpolate_speed(points: &[(u8, u8)], temp: u8) -> u8 {
+    if temp <= points[0].0 {
+        return points[0].1
+    }
+    if temp >= points[points.len() - 1].0 {
+        return points[points.len() - 1].1
+    }
+
+    for pair in points.windows(2) {
+        let (lo_temp, lo_speed) = pair[0];
+        let (hi_temp, hi_speed) = pair[1];
+
+        if temp >= lo_temp && temp <= hi_temp {
+            let t = (temp - lo_temp) as f32 / (hi_temp - lo_temp) as f32;
+            let speed = lo_speed as f32 + (hi_speed as f32 - lo_speed as f32) * t;
+            return speed.round().clamp(0.0, 100.0) as u8
         }
     }
+
+    unreachable!("temp is within the points' range")
 }
 
-fn make_command(curve: &Curve, fan: u32) -> String {
-    let mut command = "\\_SB.PCI0.SBRG.EC0.SUFC ".to_string();
-    
+fn make_command(curve: &Curve, acpi_method: &str, fan: u32) -> String {
+    let mut command = format!("{} ", acpi_method);
+
     for param_bytes in curve.curve.chunks_exact(4) {
         let mut param = param_bytes[0] as u32;
         param += (param_bytes[1] as u32) << 0x08;
@@ -346,25 +475,62 @@ fn make_command(curve: &Curve, fan: u32) -> String {
 }
 
 fn acpi_call(command: &str) -> Result<(), CurveError> {
+    acpi_call_read(command)?;
+    Ok(())
+}
+
+fn acpi_call_read(command: &str) -> Result<String, CurveError> {
     let mut file = OpenOptions::new()
         .read(true)
         .write(true)
         .open("/proc/acpi/call")?;
-    
+
     file.write_all(command.as_bytes())?;
-    
+
     file.seek(std::io::SeekFrom::Start(0))?;
-    
+
     let mut out = String::new();
     file.read_to_string(&mut out)?;
-    
+
     // dbg!(&out);
-    
+
     if out.starts_with("Error:") {
         return Err(CurveError::Acpi(out))
     }
-    
-    Ok(())
+
+    Ok(out)
+}
+
+/// Parses the dword-packed buffer returned by the EC's fan-curve read ACPI
+/// method back into a `Curve`. This is the inverse of `make_command`'s
+/// little-endian dword packing.
+fn parse_acpi_response(response: &str) -> Result<Curve, CurveError> {
+    let response = response.trim();
+    let response = response.strip_prefix('{').unwrap_or(response);
+    let response = response.strip_suffix('}').unwrap_or(response);
+
+    let mut bytes = Vec::with_capacity(16);
+    for word in response.split(',') {
+        let word = word.trim();
+        let word = word.strip_prefix("0x")
+            .ok_or_else(|| CurveError::Acpi(format!("malformed response word: {}", word)))?;
+        let dword = u32::from_str_radix(word, 16)
+            .map_err(|_| CurveError::Acpi(format!("malformed response word: {}", word)))?;
+
+        bytes.push((dword & 0xff) as u8);
+        bytes.push(((dword >> 0x08) & 0xff) as u8);
+        bytes.push(((dword >> 0x10) & 0xff) as u8);
+        bytes.push(((dword >> 0x18) & 0xff) as u8);
+    }
+
+    if bytes.len() != 16 {
+        return Err(CurveError::Acpi(format!("expected 16 bytes, got {}", bytes.len())))
+    }
+
+    let mut curve = [0u8; 16];
+    curve.copy_from_slice(&bytes);
+
+    Ok(Curve { curve })
 }
 
 #[cfg(test)]
@@ -382,9 +548,9 @@ mod tests {
             ],
         };
         
-        let command = make_command(&curve, 0x40);
+        let command = make_command(&curve, "\\_SB.PCI0.SBRG.EC0.SUFC", 0x40);
         dbg!(&command);
-        
+
         assert_eq!(&command, "\\_SB.PCI0.SBRG.EC0.SUFC 0x3c322d1e 0x645a5046 0x04040100 0x64644013 0x40")
     }
     
@@ -416,6 +582,87 @@ mod tests {
         ]);
     }
     
+    #[test]
+    fn test_parse_acpi_response() {
+        let response = "{0x3c322d1e, 0x645a5046, 0x04040100, 0x64644013}";
+
+        let curve = parse_acpi_response(response).unwrap();
+
+        assert_eq!(&curve.curve, &[
+            0x1e, 0x2d, 0x32, 0x3c,
+            0x46, 0x50, 0x5a, 0x64,
+            0x00, 0x01, 0x04, 0x04,
+            0x13, 0x40, 0x64, 0x64,
+        ]);
+    }
+
+    #[test]
+    fn test_parse_acpi_response_malformed() {
+        assert!(parse_acpi_response("{0x3c322d1e, 0x645a5046}").is_err());
+        assert!(parse_acpi_response("{not_hex}").is_err());
+    }
+
+    #[test]
+    fn from_coefficients_linear() {
+        let curve = Curve::from_coefficients(0.0, 1.0, 0.0, Fan::Cpu, None);
+
+        assert_eq!(&curve.curve, &[
+            30, 40, 50, 60,
+            70, 80, 90, 100,
+            0, 14, 29, 43,
+            57, 71, 86, 100,
+        ]);
+    }
+
+    #[test]
+    fn from_coefficients_enforces_monotonicity() {
+        // Dips below its starting value before climbing back to it: every
+        // point after the first must be raised back up to 30.
+        let curve = Curve::from_coefficients(1.0, -1.0, 0.3, Fan::Cpu, None);
+
+        assert_eq!(&curve.curve, &[
+            30, 40, 50, 60,
+            70, 80, 90, 100,
+            30, 30, 30, 30,
+            30, 30, 30, 30,
+        ]);
+    }
+
+    #[test]
+    fn from_coefficients_board_clamp_raises_points() {
+        let curve = Curve::from_coefficients(0.0, 0.0, 0.0, Fan::Cpu, Some(&board::Ga401));
+
+        assert_eq!(&curve.curve, &[
+            30, 40, 50, 60,
+            70, 80, 90, 100,
+            0, 0, 0, 0,
+            31, 49, 56, 56,
+        ]);
+    }
+
+    #[test]
+    fn default_coefficients_matches_documented_values() {
+        assert_eq!(Curve::default_coefficients(), (0.86, -0.04, 0.18));
+    }
+
+    #[test]
+    fn from_points() {
+        let curve = Curve::from_points(&[(20, 0), (50, 20), (100, 100)]).unwrap();
+
+        assert_eq!(&curve.curve, &[
+            30, 40, 50, 60,
+            70, 80, 90, 100,
+            7, 13, 20, 36,
+            52, 68, 84, 100,
+        ]);
+    }
+
+    #[test]
+    fn from_points_errors() {
+        assert!(Curve::from_points(&[(30, 0)]).is_err());
+        assert!(Curve::from_points(&[(50, 0), (30, 100)]).is_err());
+    }
+
     #[test]
     fn test_parse() {
         let config_str = "30c:1%,49c:2%,59c:3%,69c:4%,79c:31%,89c:49%,99c:56%,109c:58%";
@@ -442,16 +689,18 @@ mod tests {
     
     #[test]
     fn check_safety() {
+        let board = board::Ga401;
+
         let config_str = "30c:1%,49c:2%,59c:3%,69c:4%,79c:31%,89c:49%,99c:56%,109c:58%";
         let curve = Curve::from_config_str(config_str).unwrap();
-        assert_eq!(curve.check_safety(Fan::Cpu), Ok(()));
-        
+        assert_eq!(curve.check_safety(&board, Fan::Cpu), Ok(()));
+
         let config_str = "41c:1%,49c:2%,59c:3%,69c:4%,79c:31%,89c:49%,99c:56%,109c:58%";
         let curve = Curve::from_config_str(config_str).unwrap();
-        assert_eq!(curve.check_safety(Fan::Cpu), Err(UnsafeCurveError::TempOutOfRange(0)));
-        
+        assert_eq!(curve.check_safety(&board, Fan::Cpu), Err(UnsafeCurveError::TempOutOfRange(0)));
+
         let config_str = "30c:1%,49c:2%,59c:3%,69c:4%,79c:30%,89c:49%,99c:56%,109c:58%";
         let curve = Curve::from_config_str(config_str).unwrap();
-        assert_eq!(curve.check_safety(Fan::Cpu), Err(UnsafeCurveError::SpeedTooLow(4)));
+        assert_eq!(curve.check_safety(&board, Fan::Cpu), Err(UnsafeCurveError::SpeedTooLow(4)));
     }
 }
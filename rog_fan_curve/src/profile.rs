@@ -0,0 +1,177 @@
+//! TOML-backed storage for multiple named fan curve profiles.
+//!
+//! Requires the `config` feature (which implies `serde`).
+//!
+//! #### Example
+//!
+//! ```toml
+//! [profiles.quiet]
+//! cpu = "30c:0%,40c:1%,50c:4%,60c:4%,70c:13%,80c:40%,90c:100%,100c:100%"
+//! gpu = "30c:0%,40c:1%,50c:4%,60c:4%,70c:13%,80c:40%,90c:100%,100c:100%"
+//!
+//! [profiles.performance]
+//! cpu = "30c:10%,40c:20%,50c:30%,60c:40%,70c:60%,80c:80%,90c:100%,100c:100%"
+//! gpu = "30c:10%,40c:20%,50c:30%,60c:40%,70c:60%,80c:80%,90c:100%,100c:100%"
+//! board = "GA401IV"
+//! ```
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use serde::{ Deserialize, Serialize };
+
+use crate::{ Board, Curve, CurveError, Fan };
+
+/// The CPU and GPU curves for a single named profile, plus an optional board
+/// the profile targets.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileCurves {
+    pub cpu: Curve,
+    pub gpu: Curve,
+    pub board: Option<String>,
+}
+
+impl ProfileCurves {
+    /// Applies this profile's curves to `board`'s CPU and GPU fans.
+    ///
+    /// If this profile names a target board (`self.board`), `board.name()`
+    /// must match it, or this returns `CurveError::BoardMismatch` instead of
+    /// silently applying the curves to the wrong board.
+    pub fn apply(&self, board: &dyn Board) -> Result<(), CurveError> {
+        if let Some(expected) = &self.board {
+            if expected != board.name() {
+                return Err(CurveError::BoardMismatch {
+                    expected: expected.clone(),
+                    actual: board.name().to_string(),
+                })
+            }
+        }
+
+        self.cpu.apply(board, Fan::Cpu)?;
+        self.gpu.apply(board, Fan::Gpu)?;
+        Ok(())
+    }
+}
+
+/// A TOML document of named fan curve profiles, e.g. `[profiles.quiet]`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Profiles {
+    profiles: HashMap<String, ProfileCurves>,
+}
+
+impl Profiles {
+    /// The default profile path, `~/.config/rog_fan_curve.toml`.
+    pub fn default_path() -> Option<PathBuf> {
+        let home = std::env::var_os("HOME")?;
+        Some(Path::new(&home).join(".config/rog_fan_curve.toml"))
+    }
+
+    /// Loads profiles from a TOML file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, CurveError> {
+        let contents = fs::read_to_string(path)?;
+        toml::from_str(&contents).map_err(CurveError::Toml)
+    }
+
+    /// Saves profiles to a TOML file at `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), CurveError> {
+        let contents = toml::to_string_pretty(self).map_err(CurveError::TomlSer)?;
+        fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Gets the profile named `name`, if it exists.
+    pub fn get(&self, name: &str) -> Option<&ProfileCurves> {
+        self.profiles.get(name)
+    }
+
+    /// Applies the profile named `name` to `board` in one call.
+    pub fn apply(&self, name: &str, board: &dyn Board) -> Result<(), CurveError> {
+        let profile = self.get(name)
+            .ok_or_else(|| CurveError::UnknownProfile(name.to_string()))?;
+        profile.apply(board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::{ Ga401, FanLimits };
+
+    const SAMPLE_TOML: &str = r#"
+[profiles.quiet]
+cpu = "30c:0%,40c:1%,50c:4%,60c:4%,70c:13%,80c:40%,90c:100%,100c:100%"
+gpu = "30c:0%,40c:1%,50c:4%,60c:4%,70c:13%,80c:40%,90c:100%,100c:100%"
+
+[profiles.performance]
+cpu = "30c:10%,40c:20%,50c:30%,60c:40%,70c:60%,80c:80%,90c:100%,100c:100%"
+gpu = "30c:10%,40c:20%,50c:30%,60c:40%,70c:60%,80c:80%,90c:100%,100c:100%"
+board = "GA401IV"
+"#;
+
+    /// A board with a different name than `Ga401`, used to exercise the
+    /// board-mismatch check without touching any real hardware.
+    #[derive(Debug)]
+    struct OtherBoard;
+
+    impl Board for OtherBoard {
+        fn name(&self) -> &'static str { "OTHER_BOARD" }
+        fn acpi_method(&self) -> &str { Ga401.acpi_method() }
+        fn acpi_read_method(&self) -> &str { Ga401.acpi_read_method() }
+        fn acpi_auto_method(&self) -> &str { Ga401.acpi_auto_method() }
+        fn fan_address(&self, fan: Fan) -> Option<u32> { Ga401.fan_address(fan) }
+        fn fan_limits(&self, fan: Fan, index: u8) -> FanLimits { Ga401.fan_limits(fan, index) }
+    }
+
+    #[test]
+    fn multiple_profiles_deserialize() {
+        let profiles: Profiles = toml::from_str(SAMPLE_TOML).unwrap();
+
+        let quiet = profiles.get("quiet").unwrap();
+        assert_eq!(quiet.board, None);
+
+        let performance = profiles.get("performance").unwrap();
+        assert_eq!(performance.board.as_deref(), Some("GA401IV"));
+    }
+
+    #[test]
+    fn save_then_load_round_trips() {
+        let profiles: Profiles = toml::from_str(SAMPLE_TOML).unwrap();
+        let path = std::env::temp_dir().join("rog_fan_curve_test_save_then_load_round_trips.toml");
+
+        profiles.save(&path).unwrap();
+        let loaded = Profiles::load(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(
+            loaded.get("quiet").unwrap().cpu.as_config_string(),
+            profiles.get("quiet").unwrap().cpu.as_config_string(),
+        );
+        assert_eq!(loaded.get("performance").unwrap().board, profiles.get("performance").unwrap().board);
+    }
+
+    #[test]
+    fn get_and_apply_unknown_profile() {
+        let profiles: Profiles = toml::from_str(SAMPLE_TOML).unwrap();
+
+        assert!(profiles.get("nonexistent").is_none());
+
+        match profiles.apply("nonexistent", &Ga401) {
+            Err(CurveError::UnknownProfile(name)) => assert_eq!(name, "nonexistent"),
+            other => panic!("expected UnknownProfile, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn apply_rejects_board_mismatch() {
+        let profiles: Profiles = toml::from_str(SAMPLE_TOML).unwrap();
+
+        match profiles.apply("performance", &OtherBoard) {
+            Err(CurveError::BoardMismatch { expected, actual }) => {
+                assert_eq!(expected, "GA401IV");
+                assert_eq!(actual, "OTHER_BOARD");
+            }
+            other => panic!("expected BoardMismatch, got {:?}", other),
+        }
+    }
+}
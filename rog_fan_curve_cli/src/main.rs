@@ -3,7 +3,6 @@ use structopt::StructOpt;
 
 use rog_fan_curve::{
     Curve,
-    Board,
     Fan,
 };
 
@@ -17,14 +16,36 @@ struct RogFanCurveCli {
     gpu: bool,
     #[structopt(long)]
     no_warn: bool,
+    #[structopt(long)]
+    auto: bool,
+    #[cfg(feature = "config")]
+    #[structopt(long)]
+    profile: Option<String>,
     curve: Option<String>,
 }
 
+/// Resolves the board to use: `--board <name>` if given, otherwise the
+/// board reported by the kernel.
+fn resolve_board(board_name: &Option<String>) -> Box<dyn rog_fan_curve::Board> {
+    let board = board_name.as_ref().map(|name| rog_fan_curve::from_name(name).expect("unknown board"));
+    board.or_else(rog_fan_curve::from_board_name).expect("unknown board")
+}
+
 fn main() {
     let args = RogFanCurveCli::from_args();
-    
+
+    #[cfg(feature = "config")]
+    if let Some(profile_name) = args.profile.as_deref() {
+        let path = rog_fan_curve::Profiles::default_path()
+            .expect("couldn't determine default config path");
+        let profiles = rog_fan_curve::Profiles::load(&path).expect("failed to load profiles");
+        let board = resolve_board(&args.board);
+        profiles.apply(profile_name, board.as_ref()).expect("failed to apply profile");
+        return;
+    }
+
     let mut curve = Curve::new();
-    
+
     curve.set_point(0,  30,   0);
     curve.set_point(1,  40,   1);
     curve.set_point(2,  50,   4);
@@ -33,44 +54,48 @@ fn main() {
     curve.set_point(5,  80,  60);
     curve.set_point(6,  90, 100);
     curve.set_point(7, 100, 100);
-    
+
     if let Some(config_str) = args.curve {
         curve = Curve::from_config_str(&config_str).unwrap();
     }
-    
-    let mut board = args.board.as_ref().map(|name| Board::from_name(name).expect("unknown board"));
-    
-    if let None = board {
-        board = Board::from_board_name();
-    }
-    
-    let board = board.expect("unknown board");
-    
+
+    let board = resolve_board(&args.board);
+
     let mut cpu = args.cpu;
     let mut gpu = args.gpu;
     if !cpu && !gpu {
         cpu = true;
         gpu = true;
     }
-    
+
+    if args.auto {
+        if cpu {
+            Fan::Cpu.set_auto(board.as_ref()).unwrap();
+        }
+        if gpu {
+            Fan::Gpu.set_auto(board.as_ref()).unwrap();
+        }
+        return;
+    }
+
     let mut warning_given = false;
     if args.no_warn {
         warning_given = true;
     }
     
     if cpu {
-        if !warning_given && curve.check_safety(Fan::Cpu).is_err() {
+        if !warning_given && curve.check_safety(board.as_ref(), Fan::Cpu).is_err() {
             warning_given = true;
             eprintln!("Warning: This fan curve wouldn't be allowed in armoury crate and may be unsafe.");
         }
-        curve.apply(board, Fan::Cpu).unwrap();
+        curve.apply(board.as_ref(), Fan::Cpu).unwrap();
     }
     if gpu {
-        if !warning_given && curve.check_safety(Fan::Gpu).is_err() {
+        if !warning_given && curve.check_safety(board.as_ref(), Fan::Gpu).is_err() {
             #[allow(unused_assignments)]
             { warning_given = true; }
             eprintln!("Warning: This fan curve wouldn't be allowed in armoury crate and may be unsafe.");
         }
-        curve.apply(board, Fan::Gpu).unwrap();
+        curve.apply(board.as_ref(), Fan::Gpu).unwrap();
     }
 }